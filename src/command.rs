@@ -3,5 +3,13 @@
 pub enum ControlCommand {
     Play,
     Pause,
-    Seek(f64),
+    /// Seek to the given timestamp (ms). The `bool` is whether the decode
+    /// thread should resume paused afterward; it's decided by the UI from its
+    /// own `PlaybackState` rather than read back from the shared `DecoderState`,
+    /// since the UI sets that to `Flush` before sending this command.
+    Seek(f64, bool),
+    /// Decode and send exactly one frame forward, remaining paused.
+    StepForward,
+    /// Seek back roughly one frame and decode and send it, remaining paused.
+    StepBackward,
 }
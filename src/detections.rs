@@ -0,0 +1,208 @@
+use serde::Deserialize;
+use std::{collections::BTreeMap, fs, path::Path};
+
+/// A single detected bounding box, in source-frame pixel coordinates.
+#[derive(Debug, Clone)]
+pub struct Detection {
+    pub x: f32,
+    pub y: f32,
+    pub w: f32,
+    pub h: f32,
+    pub label: String,
+    pub score: f32,
+}
+
+/// Errors that can occur while loading a detection sidecar file.
+#[derive(Debug, Clone)]
+pub enum DetectionLoadError {
+    Io(String),
+    Parse(String),
+    UnsupportedFormat(String),
+}
+
+/// One row of a detection file, before being grouped by timestamp.
+#[derive(Debug, Deserialize)]
+struct DetectionRecord {
+    frame: f64,
+    x: f32,
+    y: f32,
+    w: f32,
+    h: f32,
+    label: String,
+    score: f32,
+}
+
+/// Per-frame detection boxes loaded from a sidecar JSON or CSV file, grouped by
+/// the timestamp (in ms) of the frame they were produced on.
+pub struct DetectionSet {
+    by_timestamp_ms: BTreeMap<u64, Vec<Detection>>,
+}
+
+impl DetectionSet {
+    /// Loads a detection file. `.json` is parsed as an array of records; `.csv`
+    /// expects a `frame,x,y,w,h,label,score` header. In both formats `frame` is
+    /// the detected-on frame's timestamp in milliseconds, matching the
+    /// `timestamp_ms` carried alongside decoded frames.
+    pub fn load(path: &Path) -> Result<Self, DetectionLoadError> {
+        let contents = fs::read_to_string(path)
+            .map_err(|e| DetectionLoadError::Io(format!("Failed to read {:?}: {}", path, e)))?;
+
+        let records = match path.extension().and_then(|ext| ext.to_str()) {
+            Some("json") => Self::parse_json(&contents)?,
+            Some("csv") => Self::parse_csv(&contents)?,
+            other => {
+                return Err(DetectionLoadError::UnsupportedFormat(format!(
+                    "Unsupported detection file extension: {:?}",
+                    other
+                )));
+            }
+        };
+
+        let mut by_timestamp_ms: BTreeMap<u64, Vec<Detection>> = BTreeMap::new();
+        for record in records {
+            by_timestamp_ms
+                .entry(record.frame.max(0.0).round() as u64)
+                .or_default()
+                .push(Detection {
+                    x: record.x,
+                    y: record.y,
+                    w: record.w,
+                    h: record.h,
+                    label: record.label,
+                    score: record.score,
+                });
+        }
+
+        Ok(Self { by_timestamp_ms })
+    }
+
+    fn parse_json(contents: &str) -> Result<Vec<DetectionRecord>, DetectionLoadError> {
+        serde_json::from_str(contents).map_err(|e| DetectionLoadError::Parse(e.to_string()))
+    }
+
+    fn parse_csv(contents: &str) -> Result<Vec<DetectionRecord>, DetectionLoadError> {
+        let mut reader = csv::Reader::from_reader(contents.as_bytes());
+        reader
+            .deserialize()
+            .collect::<Result<Vec<DetectionRecord>, csv::Error>>()
+            .map_err(|e| DetectionLoadError::Parse(e.to_string()))
+    }
+
+    /// Returns the detections belonging to the frame whose timestamp is nearest
+    /// to `timestamp_ms`, or an empty slice if none have been loaded.
+    pub fn nearest(&self, timestamp_ms: f64) -> &[Detection] {
+        let target = timestamp_ms.max(0.0).round() as u64;
+
+        let before = self.by_timestamp_ms.range(..=target).next_back();
+        let after = self.by_timestamp_ms.range(target..).next();
+
+        let nearest = match (before, after) {
+            (Some((bt, _)), Some((at, _))) => {
+                if target - bt <= at - target {
+                    before
+                } else {
+                    after
+                }
+            }
+            (Some(_), None) => before,
+            (None, Some(_)) => after,
+            (None, None) => None,
+        };
+
+        nearest.map(|(_, boxes)| boxes.as_slice()).unwrap_or(&[])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+    use tempfile::{Builder, NamedTempFile};
+
+    /// Helper to create a temporary file with the given extension and contents,
+    /// so `DetectionSet::load` can dispatch on it by extension as it would for a
+    /// real sidecar file.
+    fn write_temp_file(suffix: &str, contents: &str) -> NamedTempFile {
+        let mut file = Builder::new()
+            .suffix(suffix)
+            .tempfile()
+            .expect("Failed to create temporary file");
+        file.write_all(contents.as_bytes())
+            .expect("Failed to write to temporary file");
+        file
+    }
+
+    #[test]
+    fn test_load_json_groups_by_rounded_timestamp() {
+        let json = r#"[
+            {"frame": 1000.4, "x": 1.0, "y": 2.0, "w": 3.0, "h": 4.0, "label": "car", "score": 0.9},
+            {"frame": 2000.0, "x": 5.0, "y": 6.0, "w": 7.0, "h": 8.0, "label": "bus", "score": 0.5}
+        ]"#;
+        let file = write_temp_file(".json", json);
+        let set = DetectionSet::load(file.path()).expect("Failed to load JSON detections");
+
+        let boxes = set.nearest(1000.0);
+        assert_eq!(boxes.len(), 1);
+        assert_eq!(boxes[0].label, "car");
+    }
+
+    #[test]
+    fn test_load_csv_parses_header_and_rows() {
+        let csv = "frame,x,y,w,h,label,score\n1000,1.0,2.0,3.0,4.0,car,0.9\n";
+        let file = write_temp_file(".csv", csv);
+        let set = DetectionSet::load(file.path()).expect("Failed to load CSV detections");
+
+        let boxes = set.nearest(1000.0);
+        assert_eq!(boxes.len(), 1);
+        assert_eq!(boxes[0].label, "car");
+    }
+
+    #[test]
+    fn test_load_unsupported_extension_is_an_error() {
+        let file = write_temp_file(".txt", "irrelevant");
+        let result = DetectionSet::load(file.path());
+        assert!(matches!(result, Err(DetectionLoadError::UnsupportedFormat(_))));
+    }
+
+    #[test]
+    fn test_load_missing_file_is_an_io_error() {
+        let result = DetectionSet::load(Path::new("non_existent_detections.json"));
+        assert!(matches!(result, Err(DetectionLoadError::Io(_))));
+    }
+
+    #[test]
+    fn test_nearest_on_empty_set_returns_empty_slice() {
+        let file = write_temp_file(".json", "[]");
+        let set = DetectionSet::load(file.path()).expect("Failed to load empty detections");
+        assert!(set.nearest(12345.0).is_empty());
+    }
+
+    #[test]
+    fn test_nearest_tie_break_prefers_the_earlier_frame() {
+        let json = r#"[
+            {"frame": 1000.0, "x": 0.0, "y": 0.0, "w": 0.0, "h": 0.0, "label": "before", "score": 1.0},
+            {"frame": 1010.0, "x": 0.0, "y": 0.0, "w": 0.0, "h": 0.0, "label": "after", "score": 1.0}
+        ]"#;
+        let file = write_temp_file(".json", json);
+        let set = DetectionSet::load(file.path()).expect("Failed to load detections");
+
+        // 1005 is exactly 5ms from both 1000 and 1010; ties should favor the earlier frame.
+        let boxes = set.nearest(1005.0);
+        assert_eq!(boxes.len(), 1);
+        assert_eq!(boxes[0].label, "before");
+    }
+
+    #[test]
+    fn test_nearest_picks_the_closer_of_two_frames() {
+        let json = r#"[
+            {"frame": 1000.0, "x": 0.0, "y": 0.0, "w": 0.0, "h": 0.0, "label": "before", "score": 1.0},
+            {"frame": 1010.0, "x": 0.0, "y": 0.0, "w": 0.0, "h": 0.0, "label": "after", "score": 1.0}
+        ]"#;
+        let file = write_temp_file(".json", json);
+        let set = DetectionSet::load(file.path()).expect("Failed to load detections");
+
+        let boxes = set.nearest(1008.0);
+        assert_eq!(boxes.len(), 1);
+        assert_eq!(boxes[0].label, "after");
+    }
+}
@@ -1,4 +1,6 @@
 // Declare the new modules
+mod command;
+mod detections;
 mod ui;
 mod video_reader;
 
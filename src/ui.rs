@@ -1,7 +1,13 @@
 use crate::command::ControlCommand;
-use crate::video_reader::{VideoReader, VideoReaderError};
+use crate::detections::{Detection, DetectionSet};
+use crate::video_reader::{DecoderState, DecodingState, VideoReader, VideoReaderError};
 use eframe::egui;
 use std::sync::mpsc;
+use std::time::{Duration, Instant};
+
+/// Maximum number of decoded frames the decode thread may prefetch ahead of
+/// playback before `image_sender.send` blocks.
+const FRAME_QUEUE_CAPACITY: usize = 25;
 
 // Enum to manage the playback state.
 enum PlaybackState {
@@ -17,25 +23,101 @@ enum PlaybackState {
     Error(String),
 }
 
+/// What `poll_frames` should do with a pending frame, given the presentation
+/// clock. Split out as a pure function, separate from the egui-dependent
+/// texture upload, so the pacing/stale-drop logic can be unit tested.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum FrameDecision {
+    /// Not due yet; keep waiting for a later UI tick.
+    NotYetDue,
+    /// Due now; display this frame.
+    Display,
+    /// More than a frame behind schedule; drop it and look for a fresher one.
+    Stale,
+}
+
+/// Decides what to do with a frame timestamped `timestamp_ms`, given a
+/// presentation anchor (`anchor_instant`, `anchor_ts`) and the video's
+/// per-frame interval.
+fn frame_decision(
+    anchor_instant: Instant,
+    anchor_ts: f64,
+    timestamp_ms: f64,
+    frame_interval: Duration,
+) -> FrameDecision {
+    let target = Duration::from_secs_f64(((timestamp_ms - anchor_ts) / 1000.0).max(0.0));
+    let elapsed = anchor_instant.elapsed();
+
+    if elapsed < target {
+        FrameDecision::NotYetDue
+    } else if elapsed > target + frame_interval {
+        FrameDecision::Stale
+    } else {
+        FrameDecision::Display
+    }
+}
+
+/// How the decoded frame is scaled onto the display area.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum DisplayMode {
+    /// Scale to fit the available space, preserving aspect ratio (letterboxed).
+    Fit,
+    /// Draw at the source video's native resolution.
+    Actual,
+    /// A free zoom factor (`MyApp::zoom`), adjustable with Ctrl+mouse-wheel and
+    /// pannable by dragging.
+    Zoom,
+}
+
 pub struct MyApp {
     // Manages the video reading thread.
     video_reader: Option<VideoReader>,
-    // Receiver for video frames.
-    image_receiver: mpsc::Receiver<Result<egui::ColorImage, VideoReaderError>>,
-    // Sender for image data to be passed to the VideoReader.
-    image_sender: mpsc::Sender<Result<egui::ColorImage, VideoReaderError>>,
+    // Receiver for decoded `(frame, timestamp_ms)` pairs.
+    image_receiver: mpsc::Receiver<Result<(egui::ColorImage, f64), VideoReaderError>>,
+    // Sender for image data to be passed to the VideoReader. Bounded so the decode
+    // thread can prefetch a few frames ahead without racing arbitrarily far ahead
+    // of playback.
+    image_sender: mpsc::SyncSender<Result<(egui::ColorImage, f64), VideoReaderError>>,
     // Sender for control commands.
     control_sender: Option<mpsc::Sender<ControlCommand>>,
     // Texture to display on the screen.
     texture: Option<egui::TextureHandle>,
     // The current playback state.
     playback_state: PlaybackState,
+    // Frame rate of the loaded video, used to pace presentation.
+    fps: f64,
+    // Timestamp of the frame currently on screen.
+    current_timestamp_ms: f64,
+    // Wall-clock instant paired with the timestamp it corresponds to, used to decide
+    // when the next queued frame is due for display.
+    presentation_anchor: Option<(Instant, f64)>,
+    // A frame pulled off `image_receiver` that isn't due for display yet.
+    pending_frame: Option<(egui::ColorImage, f64)>,
+    // Shared handle on the decode thread's state, polled each frame so the UI can
+    // tell "finished" and "errored" apart instead of inferring both from the
+    // image channel disconnecting.
+    decoder_state: Option<DecoderState>,
+    // Source video dimensions, used to map detection boxes onto the displayed texture.
+    video_width: f32,
+    video_height: f32,
+    // Total duration of the loaded video, shown in the on-screen readout.
+    duration: f64,
+    // Detection boxes loaded from a sidecar file, if any.
+    detections: Option<DetectionSet>,
+    // Whether to draw the detection overlay on top of the video.
+    show_overlay: bool,
+    // How the decoded frame is scaled onto the display area.
+    display_mode: DisplayMode,
+    // Zoom factor used when `display_mode` is `DisplayMode::Zoom`.
+    zoom: f32,
+    // Pan offset (in screen pixels) applied when zoomed in, adjusted by dragging.
+    pan_offset: egui::Vec2,
 }
 
 impl Default for MyApp {
     fn default() -> Self {
-        // Create a communication channel for image data.
-        let (image_sender, image_receiver) = mpsc::channel();
+        // Create a bounded communication channel for image data.
+        let (image_sender, image_receiver) = mpsc::sync_channel(FRAME_QUEUE_CAPACITY);
         Self {
             video_reader: None,
             image_receiver,
@@ -43,42 +125,217 @@ impl Default for MyApp {
             control_sender: None,
             texture: None,
             playback_state: PlaybackState::NotLoaded,
+            fps: 30.0,
+            current_timestamp_ms: 0.0,
+            presentation_anchor: None,
+            pending_frame: None,
+            decoder_state: None,
+            video_width: 0.0,
+            video_height: 0.0,
+            duration: 0.0,
+            detections: None,
+            show_overlay: true,
+            display_mode: DisplayMode::Fit,
+            zoom: 1.0,
+            pan_offset: egui::Vec2::ZERO,
         }
     }
 }
 
-impl eframe::App for MyApp {
-    fn update(&mut self, ctx: &egui::Context, _frame: &mut eframe::Frame) {
-        // Check for a new frame from the background thread.
-        match self.image_receiver.try_recv() {
-            // Received new image data.
-            Ok(Ok(color_image)) => {
-                // Update the texture with the received image data.
-                self.texture = Some(ctx.load_texture(
-                    "video_frame",
-                    color_image,
-                    egui::TextureOptions::LINEAR,
-                ));
+impl MyApp {
+    /// Resets the presentation clock so the next displayed frame becomes the new
+    /// anchor. Used when (re)starting playback, since the next frame's timestamp
+    /// may not follow on from whatever was last shown.
+    fn reset_presentation_clock(&mut self) {
+        self.presentation_anchor = None;
+        self.pending_frame = None;
+    }
+
+    /// Issues a seek to the decode thread. Captures whether playback is currently
+    /// paused *before* touching the shared state, since that state is about to be
+    /// set to `Flush` (so any frame already in flight from before the seek is
+    /// recognized as stale) and would no longer reflect the true pre-seek pause
+    /// state by the time the decode thread looked at it. Drops anything already
+    /// queued, sends the command, then resets the presentation clock so the
+    /// post-seek frame becomes the new anchor. Draining once here is only safe
+    /// because the decode thread stops producing frames as soon as it observes
+    /// `Flush`, so nothing new lands in the channel before the `Seek` below.
+    fn seek(&mut self, ms: f64) {
+        if let (Some(sender), Some(state)) = (&self.control_sender, &self.decoder_state) {
+            let was_paused = matches!(self.playback_state, PlaybackState::Paused);
+            state.set(DecodingState::Flush);
+            while self.image_receiver.try_recv().is_ok() {}
+            let _ = sender.send(ControlCommand::Seek(ms, was_paused));
+            self.reset_presentation_clock();
+        }
+    }
+
+    /// Computes the rect the video frame should be drawn into within `available`,
+    /// preserving the source aspect ratio. `Fit` letterboxes to fill `available`;
+    /// `Actual` draws at native resolution; `Zoom` scales by `self.zoom`. In all
+    /// modes the result is centered and then shifted by `self.pan_offset`.
+    fn compute_display_rect(&self, available: egui::Rect) -> egui::Rect {
+        if self.video_width <= 0.0 || self.video_height <= 0.0 {
+            return available;
+        }
+
+        let video_size = egui::vec2(self.video_width, self.video_height);
+        let scale = match self.display_mode {
+            DisplayMode::Fit => {
+                (available.width() / video_size.x).min(available.height() / video_size.y)
             }
-            // An error occurred during video processing.
-            Ok(Err(VideoReaderError::OpenCV(msg))) => {
-                self.playback_state =
-                    PlaybackState::Error(format!("Video processing error: {}", msg));
-                self.video_reader = None;
+            DisplayMode::Actual => 1.0,
+            DisplayMode::Zoom => self.zoom,
+        };
+
+        egui::Rect::from_center_size(available.center() + self.pan_offset, video_size * scale)
+    }
+
+    /// Pulls frames from the decode thread and promotes the next on-time frame to
+    /// the display texture, pacing playback to wall-clock time using `timestamp_ms`
+    /// rather than however fast the decoder happens to produce frames.
+    fn poll_frames(&mut self, ctx: &egui::Context) {
+        // Let the decode thread's own state tell us "finished" from "errored,"
+        // rather than inferring both from the image channel disconnecting.
+        if let Some(state) = &self.decoder_state {
+            match state.get() {
+                DecodingState::End => {
+                    if matches!(
+                        self.playback_state,
+                        PlaybackState::Playing | PlaybackState::Paused
+                    ) {
+                        self.playback_state = PlaybackState::Finished;
+                    }
+                }
+                DecodingState::Error if !matches!(self.playback_state, PlaybackState::Error(_)) => {
+                    self.playback_state =
+                        PlaybackState::Error("Video processing error".to_string());
+                }
+                _ => {}
+            }
+        }
+
+        let frame_interval = if self.fps > 0.0 {
+            Duration::from_secs_f64(1.0 / self.fps)
+        } else {
+            Duration::from_millis(33)
+        };
+
+        loop {
+            if self.pending_frame.is_none() {
+                match self.image_receiver.try_recv() {
+                    Ok(Ok(frame)) => self.pending_frame = Some(frame),
+                    // An error occurred during video processing. `DecodingState::Error`
+                    // normally reports this first, but handle it here too in case the
+                    // error arrives before the next state poll.
+                    Ok(Err(VideoReaderError::OpenCV(msg))) => {
+                        self.playback_state =
+                            PlaybackState::Error(format!("Video processing error: {}", msg));
+                        self.video_reader = None;
+                        return;
+                    }
+                    // Channel disconnected: every sender, including `self.image_sender`,
+                    // has been dropped.
+                    Err(mpsc::TryRecvError::Disconnected) => {
+                        if matches!(
+                            self.playback_state,
+                            PlaybackState::Playing | PlaybackState::Paused
+                        ) {
+                            self.playback_state = PlaybackState::Finished;
+                        }
+                        self.video_reader = None;
+                        return;
+                    }
+                    // No new data has arrived yet.
+                    Err(mpsc::TryRecvError::Empty) => return,
+                }
             }
-            // Channel disconnected (video playback finished).
-            Err(mpsc::TryRecvError::Disconnected) => {
-                if matches!(self.playback_state, PlaybackState::Playing)
-                    || matches!(self.playback_state, PlaybackState::Paused)
-                {
-                    self.playback_state = PlaybackState::Finished;
+
+            let timestamp_ms = self.pending_frame.as_ref().unwrap().1;
+            let (anchor_instant, anchor_ts) = *self
+                .presentation_anchor
+                .get_or_insert((Instant::now(), timestamp_ms));
+
+            match frame_decision(anchor_instant, anchor_ts, timestamp_ms, frame_interval) {
+                FrameDecision::NotYetDue => return,
+                FrameDecision::Stale => {
+                    // We've fallen more than a frame behind schedule (e.g. after a decode
+                    // stall); skip this frame and see if a fresher one is already queued
+                    // instead of rendering stale output.
+                    self.pending_frame = None;
+                    continue;
                 }
-                self.video_reader = None;
+                FrameDecision::Display => {
+                    let (color_image, timestamp_ms) = self.pending_frame.take().unwrap();
+                    self.current_timestamp_ms = timestamp_ms;
+                    self.texture = Some(ctx.load_texture(
+                        "video_frame",
+                        color_image,
+                        egui::TextureOptions::LINEAR,
+                    ));
+                    return;
+                }
+            }
+        }
+    }
+
+    /// Draws the current frame's detection boxes and a small on-screen readout
+    /// over the displayed video image. `image_rect` is the rect the texture was
+    /// actually drawn into, used to map source-frame pixel coordinates onto it.
+    fn draw_overlay(&self, ui: &egui::Ui, image_rect: egui::Rect) {
+        let boxes: &[Detection] = self
+            .detections
+            .as_ref()
+            .map(|set| set.nearest(self.current_timestamp_ms))
+            .unwrap_or(&[]);
+
+        let painter = ui.painter();
+
+        if self.show_overlay && self.video_width > 0.0 && self.video_height > 0.0 {
+            let scale = egui::vec2(
+                image_rect.width() / self.video_width,
+                image_rect.height() / self.video_height,
+            );
+
+            for detection in boxes {
+                let rect = egui::Rect::from_min_size(
+                    image_rect.min + egui::vec2(detection.x, detection.y) * scale,
+                    egui::vec2(detection.w, detection.h) * scale,
+                );
+                let stroke = egui::Stroke::new(2.0, egui::Color32::from_rgb(0, 220, 0));
+                painter.rect_stroke(rect, 0.0, stroke);
+                painter.text(
+                    rect.left_top(),
+                    egui::Align2::LEFT_BOTTOM,
+                    format!("{} {:.0}%", detection.label, detection.score * 100.0),
+                    egui::FontId::proportional(14.0),
+                    stroke.color,
+                );
             }
-            // No new data has arrived yet.
-            Err(mpsc::TryRecvError::Empty) => {}
         }
 
+        let readout = format!(
+            "{:.1}s / {:.1}s | {:.0} fps | {} boxes",
+            self.current_timestamp_ms / 1000.0,
+            self.duration,
+            self.fps,
+            boxes.len(),
+        );
+        painter.text(
+            image_rect.right_bottom() - egui::vec2(4.0, 4.0),
+            egui::Align2::RIGHT_BOTTOM,
+            readout,
+            egui::FontId::proportional(13.0),
+            egui::Color32::WHITE,
+        );
+    }
+}
+
+impl eframe::App for MyApp {
+    fn update(&mut self, ctx: &egui::Context, _frame: &mut eframe::Frame) {
+        // Pull any frames that are due for display from the decode thread.
+        self.poll_frames(ctx);
+
         egui::CentralPanel::default().show(ctx, |ui| {
             ui.heading("Object Detection Evaluator");
 
@@ -99,9 +356,19 @@ impl eframe::App for MyApp {
                         // Create a new video reader, reusing the image channel
                         match VideoReader::new(&path, self.image_sender.clone(), control_receiver) {
                             Ok(reader) => {
+                                self.fps = reader.fps();
+                                self.video_width = reader.width() as f32;
+                                self.video_height = reader.height() as f32;
+                                self.duration = reader.duration();
+                                self.decoder_state = Some(reader.state());
                                 self.video_reader = Some(reader);
                                 self.control_sender = Some(control_sender);
                                 self.playback_state = PlaybackState::Paused; // Start in paused state
+                                self.reset_presentation_clock();
+                                // Don't carry over zoom/pan from whatever was previously loaded.
+                                self.display_mode = DisplayMode::Fit;
+                                self.zoom = 1.0;
+                                self.pan_offset = egui::Vec2::ZERO;
                             }
                             Err(VideoReaderError::OpenCV(msg)) => {
                                 self.playback_state =
@@ -124,13 +391,80 @@ impl eframe::App for MyApp {
                             if ui.button("Play").clicked() {
                                 let _ = sender.send(ControlCommand::Play);
                                 self.playback_state = PlaybackState::Playing;
+                                self.reset_presentation_clock();
                             }
                         }
                         _ => {} // Do not show buttons in other states
                     }
                 }
+
+                // Frame-stepping buttons, for precise evaluation while paused.
+                if matches!(self.playback_state, PlaybackState::Paused) {
+                    if ui.button("⏮").clicked() {
+                        if let Some(sender) = self.control_sender.clone() {
+                            self.reset_presentation_clock();
+                            let _ = sender.send(ControlCommand::StepBackward);
+                        }
+                    }
+                    if ui.button("⏭").clicked() {
+                        if let Some(sender) = self.control_sender.clone() {
+                            self.reset_presentation_clock();
+                            let _ = sender.send(ControlCommand::StepForward);
+                        }
+                    }
+                }
+
+                // Detection-overlay controls
+                if ui.button("Load Detections...").clicked() {
+                    if let Some(path) = rfd::FileDialog::new()
+                        .add_filter("Detections", &["json", "csv"])
+                        .pick_file()
+                    {
+                        match DetectionSet::load(&path) {
+                            Ok(set) => self.detections = Some(set),
+                            Err(err) => {
+                                eprintln!("Failed to load detections from {:?}: {:?}", path, err);
+                            }
+                        }
+                    }
+                }
+                ui.checkbox(&mut self.show_overlay, "Show overlay");
+            });
+
+            // Display-mode toggle: how the decoded frame is scaled onto the window.
+            ui.horizontal(|ui| {
+                ui.label("Display:");
+                for (mode, label) in [
+                    (DisplayMode::Fit, "Fit"),
+                    (DisplayMode::Actual, "Actual size"),
+                    (DisplayMode::Zoom, "Zoom"),
+                ] {
+                    if ui.selectable_value(&mut self.display_mode, mode, label).clicked() {
+                        self.zoom = 1.0;
+                        self.pan_offset = egui::Vec2::ZERO;
+                    }
+                }
             });
 
+            // Timeline scrubber: tracks the current frame's timestamp. The displayed
+            // value updates on every drag tick for responsiveness, but the actual
+            // Seek is only issued on drag release (or immediately for a plain click,
+            // which reports `changed()` without `dragged()`) so dragging doesn't
+            // flood the decode thread with a Seek per intermediate value.
+            if self.control_sender.is_some() {
+                let mut slider_value = self.current_timestamp_ms;
+                let slider =
+                    egui::Slider::new(&mut slider_value, 0.0..=(self.duration * 1000.0).max(1.0))
+                        .text("Position (ms)");
+                let response = ui.add(slider);
+                if response.changed() {
+                    self.current_timestamp_ms = slider_value;
+                }
+                if response.drag_stopped() || (response.changed() && !response.dragged()) {
+                    self.seek(slider_value);
+                }
+            }
+
             ui.separator();
 
             // Update the UI based on the current playback state.
@@ -142,8 +476,51 @@ impl eframe::App for MyApp {
                     ui.label("Please load a video file.");
                 }
                 PlaybackState::Paused | PlaybackState::Playing | PlaybackState::Finished => {
-                    if let Some(texture) = &self.texture {
-                        ui.image((texture.id(), texture.size_vec2()));
+                    if let Some(texture_id) = self.texture.as_ref().map(|t| t.id()) {
+                        // Leave a sliver of space below the video for the status label.
+                        let available = ui.available_size() - egui::vec2(0.0, 20.0);
+                        let available = egui::vec2(available.x.max(1.0), available.y.max(1.0));
+                        let (response, painter) =
+                            ui.allocate_painter(available, egui::Sense::click_and_drag());
+
+                        let display_rect = self.compute_display_rect(response.rect);
+                        painter.image(
+                            texture_id,
+                            display_rect,
+                            egui::Rect::from_min_max(egui::pos2(0.0, 0.0), egui::pos2(1.0, 1.0)),
+                            egui::Color32::WHITE,
+                        );
+
+                        if response.hovered() {
+                            let scroll = ui.input(|i| i.raw_scroll_delta.y);
+                            let ctrl = ui.input(|i| i.modifiers.ctrl);
+                            if scroll != 0.0 && ctrl {
+                                // Ctrl+wheel: free zoom.
+                                self.display_mode = DisplayMode::Zoom;
+                                let factor = if scroll > 0.0 { 1.1 } else { 1.0 / 1.1 };
+                                self.zoom = (self.zoom * factor).clamp(0.1, 8.0);
+                            } else if scroll != 0.0 && self.control_sender.is_some() {
+                                // Plain wheel: seek ±5s per notch, Shift for ±1 frame.
+                                let shift = ui.input(|i| i.modifiers.shift);
+                                let step_ms = if shift {
+                                    1000.0 / self.fps.max(1.0)
+                                } else {
+                                    5000.0
+                                };
+                                let direction = if scroll > 0.0 { 1.0 } else { -1.0 };
+                                let target = (self.current_timestamp_ms + direction * step_ms)
+                                    .clamp(0.0, self.duration * 1000.0);
+                                self.current_timestamp_ms = target;
+                                self.seek(target);
+                            }
+                        }
+
+                        // Click-drag panning while zoomed in.
+                        if self.display_mode == DisplayMode::Zoom && response.dragged() {
+                            self.pan_offset += response.drag_delta();
+                        }
+
+                        self.draw_overlay(ui, display_rect);
                     } else if !matches!(self.playback_state, PlaybackState::Finished) {
                         ui.label("Press 'Play' to start...");
                     }
@@ -158,3 +535,91 @@ impl eframe::App for MyApp {
         ctx.request_repaint();
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_frame_decision_not_yet_due() {
+        let anchor = Instant::now();
+        let decision = frame_decision(anchor, 0.0, 1000.0, Duration::from_millis(33));
+        assert_eq!(decision, FrameDecision::NotYetDue);
+    }
+
+    #[test]
+    fn test_frame_decision_display_when_on_schedule() {
+        let anchor = Instant::now() - Duration::from_millis(50);
+        let decision = frame_decision(anchor, 0.0, 50.0, Duration::from_millis(33));
+        assert_eq!(decision, FrameDecision::Display);
+    }
+
+    #[test]
+    fn test_frame_decision_stale_when_far_behind_schedule() {
+        let anchor = Instant::now() - Duration::from_millis(500);
+        let decision = frame_decision(anchor, 0.0, 10.0, Duration::from_millis(33));
+        assert_eq!(decision, FrameDecision::Stale);
+    }
+
+    #[test]
+    fn test_frame_decision_uses_anchor_timestamp_as_the_baseline() {
+        // The anchor frame was at 1000ms; a frame at 1050ms is due 50ms after
+        // the anchor instant, not 1050ms after it.
+        let anchor = Instant::now() - Duration::from_millis(50);
+        let decision = frame_decision(anchor, 1000.0, 1050.0, Duration::from_millis(33));
+        assert_eq!(decision, FrameDecision::Display);
+    }
+
+    fn app_with_video_size(width: f32, height: f32) -> MyApp {
+        let mut app = MyApp::default();
+        app.video_width = width;
+        app.video_height = height;
+        app
+    }
+
+    #[test]
+    fn test_compute_display_rect_with_no_video_loaded_returns_available() {
+        let app = app_with_video_size(0.0, 0.0);
+        let available = egui::Rect::from_min_size(egui::pos2(1.0, 2.0), egui::vec2(100.0, 50.0));
+        assert_eq!(app.compute_display_rect(available), available);
+    }
+
+    #[test]
+    fn test_compute_display_rect_fit_letterboxes_to_the_narrower_dimension() {
+        // A 2:1 video in a square window is width-constrained: scale = 100/200 = 0.5.
+        let app = app_with_video_size(200.0, 100.0);
+        let available = egui::Rect::from_min_size(egui::pos2(0.0, 0.0), egui::vec2(100.0, 100.0));
+        let rect = app.compute_display_rect(available);
+
+        assert!((rect.width() - 100.0).abs() < 0.01);
+        assert!((rect.height() - 50.0).abs() < 0.01);
+        assert!((rect.center().y - 50.0).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_compute_display_rect_actual_ignores_available_space() {
+        let mut app = app_with_video_size(640.0, 480.0);
+        app.display_mode = DisplayMode::Actual;
+        let available = egui::Rect::from_min_size(egui::pos2(0.0, 0.0), egui::vec2(100.0, 100.0));
+        let rect = app.compute_display_rect(available);
+
+        assert!((rect.width() - 640.0).abs() < 0.01);
+        assert!((rect.height() - 480.0).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_compute_display_rect_zoom_scales_and_applies_pan_offset() {
+        let mut app = app_with_video_size(100.0, 100.0);
+        app.display_mode = DisplayMode::Zoom;
+        app.zoom = 2.0;
+        app.pan_offset = egui::vec2(10.0, -5.0);
+        let available = egui::Rect::from_min_size(egui::pos2(0.0, 0.0), egui::vec2(100.0, 100.0));
+        let rect = app.compute_display_rect(available);
+
+        assert!((rect.width() - 200.0).abs() < 0.01);
+        assert!((rect.height() - 200.0).abs() < 0.01);
+        // available.center() is (50, 50); pan_offset shifts it to (60, 45).
+        assert!((rect.center().x - 60.0).abs() < 0.01);
+        assert!((rect.center().y - 45.0).abs() < 0.01);
+    }
+}
@@ -5,7 +5,15 @@ use opencv::{
     prelude::*,
     videoio::{self, VideoCapture},
 };
-use std::{path::Path, sync::mpsc, thread};
+use std::{
+    path::Path,
+    sync::{
+        atomic::{AtomicU8, Ordering},
+        mpsc, Arc,
+    },
+    thread,
+    time::Duration,
+};
 
 // Defines the error types.
 #[derive(Debug, Clone)]
@@ -13,6 +21,59 @@ pub enum VideoReaderError {
     OpenCV(String),
 }
 
+/// The decode thread's current state, polled by the UI so it can tell apart
+/// "finished," "errored," and "UI dropped the reader" instead of inferring
+/// all three from the image channel disconnecting.
+#[repr(u8)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DecodingState {
+    /// Decoding and sending frames as fast as the bounded queue allows.
+    Normal = 0,
+    /// Playback is paused; the thread is idling between control-channel checks.
+    Paused = 1,
+    /// A seek just landed; any frame already in flight from before the seek
+    /// should be treated as stale.
+    Flush = 2,
+    /// End of stream reached; no more frames will be sent. A failed read of the
+    /// capture also ends up here, since `read_next_frame` returns `Ok(None)` for it.
+    End = 3,
+    /// Converting a decoded frame failed and the decode thread has stopped.
+    /// Failed reads are reported as `End`, not here.
+    Error = 4,
+}
+
+impl From<u8> for DecodingState {
+    fn from(value: u8) -> Self {
+        match value {
+            0 => DecodingState::Normal,
+            1 => DecodingState::Paused,
+            2 => DecodingState::Flush,
+            3 => DecodingState::End,
+            _ => DecodingState::Error,
+        }
+    }
+}
+
+/// A cheaply cloneable, atomic handle on a `DecodingState`, shared between
+/// `VideoReader` and its decode thread so either side can observe or set it
+/// without going through the frame or control channels.
+#[derive(Debug, Clone)]
+pub struct DecoderState(Arc<AtomicU8>);
+
+impl DecoderState {
+    fn new(initial: DecodingState) -> Self {
+        Self(Arc::new(AtomicU8::new(initial as u8)))
+    }
+
+    pub fn get(&self) -> DecodingState {
+        DecodingState::from(self.0.load(Ordering::Acquire))
+    }
+
+    pub fn set(&self, state: DecodingState) {
+        self.0.store(state as u8, Ordering::Release);
+    }
+}
+
 /// A struct responsible for opening a video and decoding it frame by frame.
 /// This struct does not handle threading.
 pub struct FrameDecoder {
@@ -113,6 +174,8 @@ pub struct VideoReader {
     pub width: u32,
     pub height: u32,
     pub duration: f64,
+    pub fps: f64,
+    state: DecoderState,
 }
 
 impl VideoReader {
@@ -120,7 +183,10 @@ impl VideoReader {
     ///
     /// # Arguments
     /// * `path` - The path to the video file to read.
-    /// * `image_sender` - The sender to send the read frames (`egui::ColorImage`) to the UI thread.
+    /// * `image_sender` - The sender to send decoded `(frame, timestamp_ms)` pairs to the UI
+    ///   thread. This should be the sending half of a bounded (`sync_channel`) channel so the
+    ///   decode thread prefetches ahead of playback but blocks, rather than busy-looping, once
+    ///   the queue is full.
     /// * `control_receiver` - The receiver for control commands from the UI thread.
     ///
     /// # Returns
@@ -128,7 +194,7 @@ impl VideoReader {
     /// * `Err(VideoReaderError)` - If opening the video file fails.
     pub fn new(
         path: &Path,
-        image_sender: mpsc::Sender<Result<(egui::ColorImage, f64), VideoReaderError>>,
+        image_sender: mpsc::SyncSender<Result<(egui::ColorImage, f64), VideoReaderError>>,
         control_receiver: mpsc::Receiver<ControlCommand>,
     ) -> Result<Self, VideoReaderError> {
         let mut decoder = FrameDecoder::new(path)?;
@@ -137,20 +203,76 @@ impl VideoReader {
         let width = decoder.width();
         let height = decoder.height();
         let duration = decoder.duration();
-        let delay_ms = if fps > 0.0 { (1000.0 / fps) as u64 } else { 33 };
+
+        // Playback starts paused, mirroring `MyApp`'s initial `PlaybackState::Paused`.
+        let state = DecoderState::new(DecodingState::Paused);
+        let thread_state = state.clone();
 
         let thread_handle = thread::spawn(move || {
-            let mut is_paused = true;
+            // Timestamp of the most recently decoded frame, needed to compute the
+            // target position for `StepBackward`.
+            let mut current_timestamp_ms = 0.0;
+
+            // Decodes exactly one frame and sends it, tracking `current_timestamp_ms`
+            // and the `End`/`Error` states. Used both by the main playback loop and by
+            // the single-frame step/seek commands below.
+            macro_rules! decode_and_send_one {
+                () => {
+                    match decoder.read_next_frame() {
+                        Ok(Some((color_image, timestamp_ms))) => {
+                            current_timestamp_ms = timestamp_ms;
+                            image_sender.send(Ok((color_image, timestamp_ms))).is_ok()
+                        }
+                        Ok(None) => {
+                            thread_state.set(DecodingState::End);
+                            false
+                        }
+                        Err(err) => {
+                            thread_state.set(DecodingState::Error);
+                            let _ = image_sender.send(Err(err));
+                            false
+                        }
+                    }
+                };
+            }
 
             loop {
                 // Check for control commands from the UI thread.
                 match control_receiver.try_recv() {
-                    Ok(ControlCommand::Play) => is_paused = false,
-                    Ok(ControlCommand::Pause) => is_paused = true,
-                    Ok(ControlCommand::Seek(ms)) => {
+                    Ok(ControlCommand::Play) => thread_state.set(DecodingState::Normal),
+                    Ok(ControlCommand::Pause) => thread_state.set(DecodingState::Paused),
+                    Ok(ControlCommand::Seek(ms, was_paused)) => {
+                        // The UI sets `Flush` before sending this command so it can discard
+                        // any frame already in flight. `was_paused` travels with the command
+                        // rather than being read back from `thread_state` here, since by this
+                        // point `thread_state` has already been overwritten to `Flush`.
                         if decoder.cap.set(videoio::CAP_PROP_POS_MSEC, ms).is_err() {
                             eprintln!("Seek failed to position {}ms", ms);
                         }
+
+                        // OpenCV seeks land on the nearest keyframe, not necessarily `ms`
+                        // exactly; decode one frame right away so the displayed frame
+                        // updates immediately even if playback stays paused.
+                        decode_and_send_one!();
+
+                        thread_state.set(if was_paused {
+                            DecodingState::Paused
+                        } else {
+                            DecodingState::Normal
+                        });
+                    }
+                    Ok(ControlCommand::StepForward) => {
+                        // Decode and send a single frame, remaining paused.
+                        decode_and_send_one!();
+                    }
+                    Ok(ControlCommand::StepBackward) => {
+                        // OpenCV can't decode backwards, so seek just behind the current
+                        // frame and decode forward from there.
+                        let target = (current_timestamp_ms - 1000.0 / fps.max(1.0)).max(0.0);
+                        if decoder.cap.set(videoio::CAP_PROP_POS_MSEC, target).is_err() {
+                            eprintln!("Seek failed to position {}ms", target);
+                        }
+                        decode_and_send_one!();
                     }
                     Err(mpsc::TryRecvError::Disconnected) => {
                         // UI thread has disconnected, terminate.
@@ -159,29 +281,24 @@ impl VideoReader {
                     Err(mpsc::TryRecvError::Empty) => { /* No command */ }
                 }
 
-                if !is_paused {
-                    match decoder.read_next_frame() {
-                        Ok(Some((color_image, timestamp_ms))) => {
-                            // Send the converted image to the UI thread.
-                            if image_sender.send(Ok((color_image, timestamp_ms))).is_err() {
-                                // If sending fails, terminate the thread.
-                                break;
-                            }
-                        }
-                        Ok(None) => {
-                            // End of video.
-                            break;
-                        }
-                        Err(err) => {
-                            // Send the error and terminate the thread.
-                            let _ = image_sender.send(Err(err));
-                            break;
-                        }
-                    }
+                if matches!(
+                    thread_state.get(),
+                    DecodingState::Paused | DecodingState::Flush
+                ) {
+                    // `Flush` means the UI has drained the image channel and is about to
+                    // send a `Seek`; stop producing frames from the pre-seek position until
+                    // that command arrives and moves us back to `Normal`/`Paused`, otherwise
+                    // we'd keep refilling the just-drained queue with stale frames.
+                    thread::sleep(Duration::from_millis(10));
+                    continue;
                 }
 
-                // Sleep for the calculated delay to reduce CPU usage, even when paused.
-                thread::sleep(std::time::Duration::from_millis(delay_ms));
+                // Blocks once the bounded queue is full, which paces decoding to roughly
+                // match playback instead of racing ahead unbounded. Stops the thread on
+                // EOF, a read error, or the UI dropping the image channel.
+                if !decode_and_send_one!() {
+                    break;
+                }
             }
         });
 
@@ -190,6 +307,8 @@ impl VideoReader {
             width,
             height,
             duration,
+            fps,
+            state,
         })
     }
 
@@ -201,9 +320,18 @@ impl VideoReader {
         self.height
     }
 
+    pub fn fps(&self) -> f64 {
+        self.fps
+    }
+
     pub fn duration(&self) -> f64 {
         self.duration
     }
+
+    /// Returns a clone of the shared decoding state so the UI can poll it.
+    pub fn state(&self) -> DecoderState {
+        self.state.clone()
+    }
 }
 
 #[cfg(test)]
@@ -248,6 +376,36 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_decoding_state_from_u8_round_trip() {
+        assert_eq!(DecodingState::from(0), DecodingState::Normal);
+        assert_eq!(DecodingState::from(1), DecodingState::Paused);
+        assert_eq!(DecodingState::from(2), DecodingState::Flush);
+        assert_eq!(DecodingState::from(3), DecodingState::End);
+        assert_eq!(DecodingState::from(4), DecodingState::Error);
+        // Anything out of the known range falls back to `Error` rather than panicking.
+        assert_eq!(DecodingState::from(255), DecodingState::Error);
+    }
+
+    #[test]
+    fn test_decoder_state_get_reflects_latest_set() {
+        let state = DecoderState::new(DecodingState::Paused);
+        assert_eq!(state.get(), DecodingState::Paused);
+
+        state.set(DecodingState::Normal);
+        assert_eq!(state.get(), DecodingState::Normal);
+    }
+
+    #[test]
+    fn test_decoder_state_clone_shares_the_same_underlying_state() {
+        let state = DecoderState::new(DecodingState::Normal);
+        let clone = state.clone();
+
+        clone.set(DecodingState::End);
+
+        assert_eq!(state.get(), DecodingState::End);
+    }
+
     #[test]
     fn test_frame_decoder_read_next_frame_empty_file() {
         let empty_file_path = create_empty_temp_file();